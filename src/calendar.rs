@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use chrono::{DateTime, NaiveTime, TimeZone};
+use chrono_tz::{America, Tz};
+
+use crate::error::{Result, ThalesError};
+
+/// The market session an option's expiration is anchored to: the wall-clock
+/// close time and the timezone it's observed in.
+///
+/// Defaults to the 16:00 America/New_York close used by US equity options;
+/// non-US or non-equity contracts can supply their own.
+#[derive(Debug, Clone, Copy)]
+pub struct MarketSession {
+    pub close_time: NaiveTime,
+    pub tz: Tz,
+}
+
+impl Default for MarketSession {
+    fn default() -> Self {
+        MarketSession {
+            close_time: NaiveTime::from_hms_opt(16, 0, 0).expect("16:00:00 is a valid time"),
+            tz: America::New_York,
+        }
+    }
+}
+
+impl MarketSession {
+    /// Anchors a `NaiveDate` to this session's close time and timezone.
+    ///
+    /// Fails with `ThalesError::InvalidCloseTime` if `close_time` falls in a
+    /// DST spring-forward gap on `date` in `tz`, since no such wall-clock
+    /// moment exists.
+    pub fn anchor(&self, date: chrono::NaiveDate) -> Result<DateTime<Tz>> {
+        let naive = date.and_time(self.close_time);
+        self.tz.from_local_datetime(&naive).earliest().ok_or_else(|| {
+            ThalesError::InvalidCloseTime(format!(
+                "{} {} does not exist in {}",
+                date, self.close_time, self.tz
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn default_session_anchors_to_the_new_york_close() {
+        let session = MarketSession::default();
+        let anchored = session.anchor(NaiveDate::from_ymd_opt(2024, 1, 19).unwrap()).unwrap();
+
+        assert_eq!(session.tz, America::New_York);
+        assert_eq!(anchored.naive_local().time(), session.close_time);
+    }
+
+    #[test]
+    fn rejects_a_close_time_that_falls_in_a_dst_spring_forward_gap() {
+        // US clocks spring forward at 02:00 on 2024-03-10, so 02:30 never
+        // occurs in America/New_York on that date.
+        let session = MarketSession {
+            close_time: NaiveTime::from_hms_opt(2, 30, 0).unwrap(),
+            tz: America::New_York,
+        };
+
+        assert!(matches!(
+            session.anchor(NaiveDate::from_ymd_opt(2024, 3, 10).unwrap()),
+            Err(ThalesError::InvalidCloseTime(_))
+        ));
+    }
+}
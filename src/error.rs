@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+/// The error type for all fallible operations in this crate.
+#[derive(Debug)]
+pub enum ThalesError {
+    /// A date string could not be parsed by `chrono`.
+    ChronoParseError(String),
+    /// A call/put indicator did not match a known `OptionType`.
+    InvalidOptionType(String),
+    /// An option symbol was malformed (e.g. the wrong length).
+    MalformedSymbol(String),
+    /// A strike field could not be parsed as a number.
+    InvalidStrike(String),
+    /// A CSV row could not be read or deserialized into a `PositionRow`.
+    InvalidCsvRow(String),
+    /// A market session's close time does not exist in its timezone on a
+    /// given date (e.g. it falls in a DST spring-forward gap).
+    InvalidCloseTime(String),
+}
+
+impl fmt::Display for ThalesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThalesError::ChronoParseError(msg) => write!(f, "date parse error: {}", msg),
+            ThalesError::InvalidOptionType(msg) => write!(f, "invalid option type: {}", msg),
+            ThalesError::MalformedSymbol(msg) => write!(f, "malformed option symbol: {}", msg),
+            ThalesError::InvalidStrike(msg) => write!(f, "invalid strike: {}", msg),
+            ThalesError::InvalidCsvRow(msg) => write!(f, "invalid CSV row: {}", msg),
+            ThalesError::InvalidCloseTime(msg) => write!(f, "invalid market close time: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ThalesError {}
+
+impl From<chrono::ParseError> for ThalesError {
+    fn from(e: chrono::ParseError) -> Self {
+        ThalesError::ChronoParseError(e.to_string())
+    }
+}
+
+/// A `Result` alias using `ThalesError`, returned by every fallible
+/// constructor in this crate.
+pub type Result<T> = std::result::Result<T, ThalesError>;
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn chrono_parse_error_converts_via_from() {
+        let err: ThalesError = NaiveDate::parse_from_str("not-a-date", "%Y-%m-%d")
+            .unwrap_err()
+            .into();
+
+        assert!(matches!(err, ThalesError::ChronoParseError(_)));
+    }
+
+    #[test]
+    fn display_includes_the_inner_message() {
+        let err = ThalesError::InvalidStrike("abc".to_string());
+        assert_eq!(err.to_string(), "invalid strike: abc");
+    }
+
+    #[test]
+    fn implements_std_error() {
+        fn assert_is_error<E: std::error::Error>(_: &E) {}
+        assert_is_error(&ThalesError::MalformedSymbol("x".to_string()));
+    }
+}
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+
+pub mod calendar;
+pub mod error;
+pub mod option;
+pub mod portfolio;
+pub mod pricing;
+
+pub use calendar::MarketSession;
+pub use error::{Result, ThalesError};
+pub use option::{Option, OptionType};
+pub use pricing::{Greeks, Market};
@@ -1,6 +1,13 @@
 // SPDX-License-Identifier: MIT
 
-use chrono::NaiveDate;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+use crate::calendar::MarketSession;
+use crate::error::{Result, ThalesError};
 
 #[derive(Debug, PartialEq)]
 pub enum OptionType {
@@ -11,17 +18,17 @@ pub enum OptionType {
 #[derive(Debug)]
 pub struct Option {
     pub symbol: String,
-    pub type: OptionType,
+    pub r#type: OptionType,
     pub strike: f64,
     pub expiration: NaiveDate,
     pub size: u32,
 }
 
 impl Option {
-    pub fn new(symbol: String, type: OptionType, strike: f64, expiration: NaiveDate, size: u32) -> Self {
+    pub fn new(symbol: String, r#type: OptionType, strike: f64, expiration: NaiveDate, size: u32) -> Self {
         Option {
             symbol,
-            type,
+            r#type,
             strike,
             expiration,
             size,
@@ -31,9 +38,143 @@ impl Option {
     pub fn description(&self) -> String {
         format!("{} {} ${} {}",
             self.symbol,
-            if self.type == OptionType::Call { "Call" } else { "Put" },
+            if self.r#type == OptionType::Call { "Call" } else { "Put" },
             self.strike,
             self.expiration.format("%Y-%m-%d")
         )
     }
+
+    /// Parses an OCC-standard 21-character option symbol, e.g.
+    /// `AAPL  240119C00195000`, into an `Option`.
+    ///
+    /// The layout is: a 6-character underlying root (space-padded on the
+    /// right), a 6-digit `YYMMDD` expiration, a single `C`/`P` byte, and an
+    /// 8-digit strike price scaled by 1000.
+    pub fn from_occ(occ: &str) -> Result<Self> {
+        if !occ.is_ascii() || occ.len() != 21 {
+            return Err(ThalesError::MalformedSymbol(format!(
+                "OCC symbol must be 21 ASCII characters, got {:?}",
+                occ
+            )));
+        }
+
+        let root = occ[0..6].trim_end().to_string();
+        let date = &occ[6..12];
+        let type_byte = &occ[12..13];
+        let strike_digits = &occ[13..21];
+
+        let expiration = NaiveDate::parse_from_str(date, "%y%m%d")?;
+
+        let type_ = match type_byte {
+            "C" => OptionType::Call,
+            "P" => OptionType::Put,
+            other => return Err(ThalesError::InvalidOptionType(other.to_string())),
+        };
+
+        let strike_thousandths: u64 = strike_digits
+            .parse()
+            .map_err(|_| ThalesError::InvalidStrike(strike_digits.to_string()))?;
+        let strike = strike_thousandths as f64 / 1000.0;
+
+        Ok(Option::new(root, type_, strike, expiration, 0))
+    }
+
+    /// Serializes this option to its 21-character OCC symbol.
+    pub fn to_occ(&self) -> String {
+        let root = format!("{:<6}", self.symbol.chars().take(6).collect::<String>());
+        let date = self.expiration.format("%y%m%d").to_string();
+        let type_byte = if self.r#type == OptionType::Call { "C" } else { "P" };
+        let strike_thousandths = (self.strike * 1000.0).round() as u64;
+
+        format!("{}{}{}{:08}", root, date, type_byte, strike_thousandths)
+    }
+
+    /// This option's expiration anchored to a wall-clock close time and
+    /// timezone, per `session`.
+    pub fn expiration_datetime(&self, session: &MarketSession) -> Result<DateTime<Tz>> {
+        session.anchor(self.expiration)
+    }
+
+    /// Fractional years between `now` and this option's expiration, anchored
+    /// to `session`'s close time and timezone rather than a whole-day
+    /// approximation.
+    pub fn time_to_expiry(&self, now: DateTime<Utc>, session: &MarketSession) -> Result<f64> {
+        let expiry = self.expiration_datetime(session)?.with_timezone(&Utc);
+        Ok((expiry - now).num_seconds() as f64 / (365.0 * 24.0 * 60.0 * 60.0))
+    }
+}
+
+impl FromStr for Option {
+    type Err = ThalesError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Option::from_occ(s)
+    }
+}
+
+impl fmt::Display for Option {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_occ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_occ_parses_call() {
+        let opt = Option::from_occ("AAPL  240119C00195000").unwrap();
+        assert_eq!(opt.symbol, "AAPL");
+        assert_eq!(opt.r#type, OptionType::Call);
+        assert_eq!(opt.strike, 195.0);
+        assert_eq!(opt.expiration, NaiveDate::from_ymd_opt(2024, 1, 19).unwrap());
+    }
+
+    #[test]
+    fn occ_round_trips_through_to_occ() {
+        let original = "AAPL  240119C00195000";
+        let opt = Option::from_occ(original).unwrap();
+        assert_eq!(opt.to_occ(), original);
+    }
+
+    #[test]
+    fn occ_round_trips_through_display_and_from_str() {
+        let opt = Option::new(
+            "SPX".to_string(),
+            OptionType::Put,
+            12.5,
+            NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
+            1,
+        );
+        let roundtripped: Option = opt.to_string().parse().unwrap();
+        assert_eq!(roundtripped.symbol, "SPX");
+        assert_eq!(roundtripped.r#type, OptionType::Put);
+        assert_eq!(roundtripped.strike, 12.5);
+        assert_eq!(roundtripped.expiration, opt.expiration);
+    }
+
+    #[test]
+    fn from_occ_rejects_wrong_length() {
+        assert!(matches!(
+            Option::from_occ("AAPL"),
+            Err(ThalesError::MalformedSymbol(_))
+        ));
+    }
+
+    #[test]
+    fn from_occ_rejects_non_ascii_without_panicking() {
+        assert!(matches!(
+            Option::from_occ("AAAAA\u{e9}XXXXXXXXXXXXXX"),
+            Err(ThalesError::MalformedSymbol(_))
+        ));
+    }
+
+    #[test]
+    fn from_occ_rejects_bad_type_byte() {
+        assert!(matches!(
+            Option::from_occ("AAPL  240119X00195000"),
+            Err(ThalesError::InvalidOptionType(_))
+        ));
+    }
 }
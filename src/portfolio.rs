@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+use std::io::Read;
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::error::{Result, ThalesError};
+use crate::option::{Option, OptionType};
+
+/// A single row of a brokerage position export, following the column
+/// layout tastyworks and similar brokers use.
+#[derive(Debug, Deserialize)]
+struct PositionRow {
+    #[serde(rename = "Symbol")]
+    symbol: String,
+    #[serde(rename = "Call/Put")]
+    call_put: String,
+    #[serde(rename = "Strike Price")]
+    strike: f64,
+    #[serde(rename = "Expiration")]
+    expiration: String,
+    #[serde(rename = "Quantity")]
+    quantity: u32,
+}
+
+impl Option {
+    /// Loads a brokerage position export (CSV) into a list of `Option`s.
+    ///
+    /// Expects `Symbol`, `Call/Put`, `Strike Price`, `Expiration`, and
+    /// `Quantity` columns; `Call/Put` is matched case-insensitively and
+    /// `Expiration` is parsed as `MM/DD/YYYY`.
+    pub fn from_csv_reader<R: Read>(r: R) -> Result<Vec<Self>> {
+        let mut reader = csv::Reader::from_reader(r);
+        let mut options = Vec::new();
+
+        for result in reader.deserialize() {
+            let row: PositionRow =
+                result.map_err(|e| ThalesError::InvalidCsvRow(e.to_string()))?;
+
+            let type_ = match row.call_put.to_uppercase().as_str() {
+                "CALL" | "C" => OptionType::Call,
+                "PUT" | "P" => OptionType::Put,
+                other => return Err(ThalesError::InvalidOptionType(other.to_string())),
+            };
+
+            let expiration = NaiveDate::parse_from_str(&row.expiration, "%m/%d/%Y")?;
+
+            options.push(Option::new(row.symbol, type_, row.strike, expiration, row.quantity));
+        }
+
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_calls_and_puts_case_insensitively() {
+        let csv = "\
+Symbol,Call/Put,Strike Price,Expiration,Quantity
+AAPL,Call,195,01/19/2024,2
+SPX,put,4500,03/15/2024,1
+";
+        let options = Option::from_csv_reader(csv.as_bytes()).unwrap();
+
+        assert_eq!(options.len(), 2);
+        assert_eq!(options[0].symbol, "AAPL");
+        assert_eq!(options[0].r#type, OptionType::Call);
+        assert_eq!(options[0].strike, 195.0);
+        assert_eq!(options[0].expiration, NaiveDate::from_ymd_opt(2024, 1, 19).unwrap());
+        assert_eq!(options[0].size, 2);
+
+        assert_eq!(options[1].symbol, "SPX");
+        assert_eq!(options[1].r#type, OptionType::Put);
+        assert_eq!(options[1].size, 1);
+    }
+
+    #[test]
+    fn rejects_invalid_call_put_value() {
+        let csv = "\
+Symbol,Call/Put,Strike Price,Expiration,Quantity
+AAPL,Straddle,195,01/19/2024,2
+";
+        assert!(matches!(
+            Option::from_csv_reader(csv.as_bytes()),
+            Err(ThalesError::InvalidOptionType(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_csv_row() {
+        let csv = "\
+Symbol,Call/Put,Strike Price,Expiration,Quantity
+AAPL,Call,not-a-number,01/19/2024,2
+";
+        assert!(matches!(
+            Option::from_csv_reader(csv.as_bytes()),
+            Err(ThalesError::InvalidCsvRow(_))
+        ));
+    }
+}
@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT
+
+use chrono::{DateTime, Utc};
+
+use crate::calendar::MarketSession;
+use crate::error::Result;
+use crate::option::{Option, OptionType};
+
+/// The market inputs needed to price an option under Black-Scholes.
+#[derive(Debug, Clone, Copy)]
+pub struct Market {
+    pub spot: f64,
+    pub risk_free_rate: f64,
+    pub volatility: f64,
+}
+
+/// The option's sensitivities to the Black-Scholes inputs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub theta: f64,
+    pub vega: f64,
+    pub rho: f64,
+}
+
+/// Standard normal cumulative distribution function, via the
+/// Abramowitz-Stegun 7.1.26 approximation of `erf`.
+fn norm_cdf(x: f64) -> f64 {
+    let a1 = 0.254829592;
+    let a2 = -0.284496736;
+    let a3 = 1.421413741;
+    let a4 = -1.453152027;
+    let a5 = 1.061405429;
+    let p = 0.3275911;
+
+    let z = x / std::f64::consts::SQRT_2;
+    let sign = if z < 0.0 { -1.0 } else { 1.0 };
+    let z = z.abs();
+
+    let t = 1.0 / (1.0 + p * z);
+    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-z * z).exp();
+
+    0.5 * (1.0 + sign * y)
+}
+
+/// Standard normal probability density function.
+fn norm_pdf(x: f64) -> f64 {
+    (-x * x / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Below this, volatility is treated as zero: `d1`/`d2` divide by
+/// `sigma * sqrt(T)`, which is otherwise 0 and yields `NaN`.
+const MIN_VOLATILITY: f64 = 1e-9;
+
+/// The deterministic forward price implied by a riskless drift, used when
+/// volatility is (near) zero and the Black-Scholes formula degenerates.
+fn forward_price(s: f64, r: f64, t: f64) -> f64 {
+    s * (r * t).exp()
+}
+
+impl Option {
+    /// Theoretical Black-Scholes value of this option, as of `now`.
+    pub fn price(&self, mkt: &Market, now: DateTime<Utc>, session: &MarketSession) -> Result<f64> {
+        let t = self.time_to_expiry(now, session)?;
+        let intrinsic = match self.r#type {
+            OptionType::Call => (mkt.spot - self.strike).max(0.0),
+            OptionType::Put => (self.strike - mkt.spot).max(0.0),
+        };
+        if t <= 0.0 {
+            return Ok(intrinsic);
+        }
+
+        let (s, k, r, sigma) = (mkt.spot, self.strike, mkt.risk_free_rate, mkt.volatility);
+        if sigma.abs() < MIN_VOLATILITY {
+            let payoff = match self.r#type {
+                OptionType::Call => (forward_price(s, r, t) - k).max(0.0),
+                OptionType::Put => (k - forward_price(s, r, t)).max(0.0),
+            };
+            return Ok((-r * t).exp() * payoff);
+        }
+
+        let d1 = ((s / k).ln() + (r + sigma * sigma / 2.0) * t) / (sigma * t.sqrt());
+        let d2 = d1 - sigma * t.sqrt();
+
+        Ok(match self.r#type {
+            OptionType::Call => s * norm_cdf(d1) - k * (-r * t).exp() * norm_cdf(d2),
+            OptionType::Put => k * (-r * t).exp() * norm_cdf(-d2) - s * norm_cdf(-d1),
+        })
+    }
+
+    /// Black-Scholes Greeks for this option, as of `now`.
+    pub fn greeks(&self, mkt: &Market, now: DateTime<Utc>, session: &MarketSession) -> Result<Greeks> {
+        let t = self.time_to_expiry(now, session)?;
+        if t <= 0.0 {
+            return Ok(Greeks {
+                delta: 0.0,
+                gamma: 0.0,
+                theta: 0.0,
+                vega: 0.0,
+                rho: 0.0,
+            });
+        }
+
+        let (s, k, r, sigma) = (mkt.spot, self.strike, mkt.risk_free_rate, mkt.volatility);
+        if sigma.abs() < MIN_VOLATILITY {
+            let forward = forward_price(s, r, t);
+            let delta = match self.r#type {
+                OptionType::Call if forward > k => 1.0,
+                OptionType::Call => 0.0,
+                OptionType::Put if forward < k => -1.0,
+                OptionType::Put => 0.0,
+            };
+            return Ok(Greeks {
+                delta,
+                gamma: 0.0,
+                theta: 0.0,
+                vega: 0.0,
+                rho: 0.0,
+            });
+        }
+
+        let sqrt_t = t.sqrt();
+        let d1 = ((s / k).ln() + (r + sigma * sigma / 2.0) * t) / (sigma * sqrt_t);
+        let d2 = d1 - sigma * sqrt_t;
+        let discount = (-r * t).exp();
+
+        let (delta, theta, rho) = match self.r#type {
+            OptionType::Call => (
+                norm_cdf(d1),
+                -(s * norm_pdf(d1) * sigma) / (2.0 * sqrt_t) - r * k * discount * norm_cdf(d2),
+                k * t * discount * norm_cdf(d2),
+            ),
+            OptionType::Put => (
+                norm_cdf(d1) - 1.0,
+                -(s * norm_pdf(d1) * sigma) / (2.0 * sqrt_t) + r * k * discount * norm_cdf(-d2),
+                -k * t * discount * norm_cdf(-d2),
+            ),
+        };
+
+        Ok(Greeks {
+            delta,
+            gamma: norm_pdf(d1) / (s * sigma * sqrt_t),
+            theta,
+            vega: s * norm_pdf(d1) * sqrt_t,
+            rho,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone};
+
+    use super::*;
+
+    fn at_the_money_call(expiration: NaiveDate) -> Option {
+        Option::new("TEST".to_string(), OptionType::Call, 100.0, expiration, 1)
+    }
+
+    fn at_the_money_put(expiration: NaiveDate) -> Option {
+        Option::new("TEST".to_string(), OptionType::Put, 100.0, expiration, 1)
+    }
+
+    #[test]
+    fn call_put_parity_holds() {
+        let mkt = Market {
+            spot: 100.0,
+            risk_free_rate: 0.05,
+            volatility: 0.2,
+        };
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let expiration = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let session = MarketSession::default();
+
+        let option = at_the_money_call(expiration);
+        let call_price = option.price(&mkt, now, &session).unwrap();
+        let put_price = at_the_money_put(expiration).price(&mkt, now, &session).unwrap();
+        let t = option.time_to_expiry(now, &session).unwrap();
+
+        // Put-call parity: C - P = S - K*e^(-rT)
+        let parity_rhs = mkt.spot - option.strike * (-mkt.risk_free_rate * t).exp();
+        assert!((call_price - put_price - parity_rhs).abs() < 1e-6);
+    }
+
+    #[test]
+    fn call_delta_is_between_zero_and_one() {
+        let mkt = Market {
+            spot: 100.0,
+            risk_free_rate: 0.05,
+            volatility: 0.2,
+        };
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let expiration = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let greeks = at_the_money_call(expiration)
+            .greeks(&mkt, now, &MarketSession::default())
+            .unwrap();
+
+        assert!(greeks.delta > 0.0 && greeks.delta < 1.0);
+    }
+
+    #[test]
+    fn expired_option_prices_at_intrinsic_value_with_zero_greeks() {
+        let mkt = Market {
+            spot: 110.0,
+            risk_free_rate: 0.05,
+            volatility: 0.2,
+        };
+        let expiration = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let session = MarketSession::default();
+
+        let call = at_the_money_call(expiration);
+        assert_eq!(call.price(&mkt, now, &session).unwrap(), 10.0);
+        assert_eq!(
+            call.greeks(&mkt, now, &session).unwrap(),
+            Greeks { delta: 0.0, gamma: 0.0, theta: 0.0, vega: 0.0, rho: 0.0 }
+        );
+    }
+
+    #[test]
+    fn zero_volatility_does_not_produce_nan() {
+        let mkt = Market {
+            spot: 110.0,
+            risk_free_rate: 0.05,
+            volatility: 0.0,
+        };
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let expiration = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let session = MarketSession::default();
+
+        let call = at_the_money_call(expiration);
+        let price = call.price(&mkt, now, &session).unwrap();
+        let greeks = call.greeks(&mkt, now, &session).unwrap();
+
+        assert!(price.is_finite());
+        assert!(greeks.delta.is_finite());
+        assert!(greeks.gamma.is_finite());
+        assert!(greeks.theta.is_finite());
+        assert!(greeks.vega.is_finite());
+        assert!(greeks.rho.is_finite());
+    }
+}